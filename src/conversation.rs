@@ -0,0 +1,78 @@
+//! Stateful multi-turn conversations.
+//!
+//! Many effective jailbreaks (e.g. "Crescendo") don't work in a single shot; they escalate
+//! gradually across several turns, steering the model with its own prior replies. The
+//! [`Session`] type tracks that running history against a single [`Target`].
+
+use crate::target::Target;
+use crate::RedOxideResult;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Who sent a given [`ChatMessage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Role {
+    User,
+    Assistant,
+}
+
+/// A single turn in a conversation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatMessage {
+    pub role: Role,
+    pub content: String,
+}
+
+/// Tracks an ordered, growing conversation against a single [`Target`].
+///
+/// Unlike [`Target::send_prompt`], which is stateless, a `Session` remembers every prior
+/// turn so that providers can be sent the full message history rather than a lone user
+/// message, which is what lets multi-turn attacks build on themselves.
+pub struct Session {
+    target: Arc<dyn Target>,
+    history: Vec<ChatMessage>,
+}
+
+impl Session {
+    /// Starts a new, empty conversation against `target`.
+    pub fn new(target: Arc<dyn Target>) -> Self {
+        Self {
+            target,
+            history: Vec::new(),
+        }
+    }
+
+    /// The full transcript so far, oldest turn first.
+    pub fn history(&self) -> &[ChatMessage] {
+        &self.history
+    }
+
+    /// Discards any turns appended after `len`.
+    ///
+    /// Used by callers that retry a failed [`Session::send_turn`]: the user turn is pushed
+    /// before the target is contacted, so a failed send must roll that turn back before
+    /// trying again, otherwise a retried prompt would appear twice in history.
+    pub(crate) fn truncate_history(&mut self, len: usize) {
+        self.history.truncate(len);
+    }
+
+    /// Sends `prompt` as the next user turn and appends the reply to history.
+    ///
+    /// The target receives the entire conversation so far via
+    /// [`Target::send_conversation`], not just this latest prompt.
+    pub async fn send_turn(&mut self, prompt: &str) -> RedOxideResult<String> {
+        self.history.push(ChatMessage {
+            role: Role::User,
+            content: prompt.to_string(),
+        });
+
+        let reply = self.target.send_conversation(&self.history).await?;
+
+        self.history.push(ChatMessage {
+            role: Role::Assistant,
+            content: reply.clone(),
+        });
+
+        Ok(reply)
+    }
+}
@@ -47,8 +47,11 @@
 //! }
 //! ```
 
+pub mod conversation;
 pub mod evaluator;
+pub mod metrics;
 pub mod runner;
+pub mod server;
 pub mod strategy;
 pub mod target;
 
@@ -76,4 +79,18 @@ pub struct AttackResult {
 
     /// The name of the strategy used (e.g., "Template-Based Jailbreak").
     pub strategy_name: String,
+
+    /// The full multi-turn conversation that led to this result, as `(prompt, reply)` pairs
+    /// in order.
+    ///
+    /// Empty for single-shot strategies; populated for conversational attacks (e.g.
+    /// Crescendo) so a successful escalation can be replayed turn by turn.
+    #[serde(default)]
+    pub transcript: Vec<(String, String)>,
+
+    /// Any tool/function calls the target requested in response to the prompt.
+    ///
+    /// Empty unless the attack was run via [`crate::target::Target::send_prompt_with_tools`].
+    #[serde(default)]
+    pub tool_calls: Vec<crate::target::ToolCall>,
 }
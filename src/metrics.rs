@@ -0,0 +1,128 @@
+//! Prometheus metrics and an optional `/metrics` endpoint for the [`crate::runner::Runner`].
+//!
+//! For long scans against rate-limited APIs, operators need live visibility into throughput,
+//! success rate, latency, and error counts without tailing logs. This module wraps a small
+//! `prometheus` registry and, when enabled, serves it over HTTP so it can be scraped into a
+//! CI dashboard.
+
+use prometheus::{CounterVec, HistogramOpts, HistogramVec, Opts, Registry, TextEncoder};
+use std::convert::Infallible;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+/// Tracks prompts sent, attacks succeeded, requests failed, and per-request latency, all
+/// labeled by `strategy` and target `model`.
+#[derive(Clone)]
+pub struct Metrics {
+    registry: Registry,
+    prompts_sent: CounterVec,
+    attacks_succeeded: CounterVec,
+    requests_failed: CounterVec,
+    request_latency: HistogramVec,
+}
+
+impl Metrics {
+    /// Creates a new metrics set registered against a fresh Prometheus [`Registry`].
+    pub fn new() -> RegistryResult<Self> {
+        let registry = Registry::new();
+        let labels = &["strategy", "model"];
+
+        let prompts_sent = CounterVec::new(
+            Opts::new("redoxide_prompts_sent_total", "Total prompts sent to a target"),
+            labels,
+        )?;
+        let attacks_succeeded = CounterVec::new(
+            Opts::new(
+                "redoxide_attacks_succeeded_total",
+                "Total attacks the evaluator flagged as successful",
+            ),
+            labels,
+        )?;
+        let requests_failed = CounterVec::new(
+            Opts::new(
+                "redoxide_requests_failed_total",
+                "Total requests that failed after exhausting retries",
+            ),
+            labels,
+        )?;
+        let request_latency = HistogramVec::new(
+            HistogramOpts::new(
+                "redoxide_request_latency_seconds",
+                "Latency of a single prompt request",
+            ),
+            labels,
+        )?;
+
+        registry.register(Box::new(prompts_sent.clone()))?;
+        registry.register(Box::new(attacks_succeeded.clone()))?;
+        registry.register(Box::new(requests_failed.clone()))?;
+        registry.register(Box::new(request_latency.clone()))?;
+
+        Ok(Self {
+            registry,
+            prompts_sent,
+            attacks_succeeded,
+            requests_failed,
+            request_latency,
+        })
+    }
+
+    pub fn record_prompt_sent(&self, strategy: &str, model: &str) {
+        self.prompts_sent.with_label_values(&[strategy, model]).inc();
+    }
+
+    pub fn record_attack_succeeded(&self, strategy: &str, model: &str) {
+        self.attacks_succeeded
+            .with_label_values(&[strategy, model])
+            .inc();
+    }
+
+    pub fn record_request_failed(&self, strategy: &str, model: &str) {
+        self.requests_failed
+            .with_label_values(&[strategy, model])
+            .inc();
+    }
+
+    pub fn observe_latency(&self, strategy: &str, model: &str, seconds: f64) {
+        self.request_latency
+            .with_label_values(&[strategy, model])
+            .observe(seconds);
+    }
+
+    /// Renders the current metrics in Prometheus's text exposition format.
+    fn render(&self) -> String {
+        let encoder = TextEncoder::new();
+        let metric_families = self.registry.gather();
+        encoder
+            .encode_to_string(&metric_families)
+            .unwrap_or_default()
+    }
+
+    /// Serves these metrics over `/metrics` on `addr` until the process exits.
+    ///
+    /// Intended to be spawned as a background task (e.g. `tokio::spawn(metrics.serve(addr))`)
+    /// alongside a [`crate::runner::Runner`] scan.
+    pub async fn serve(self: Arc<Self>, addr: SocketAddr) -> std::io::Result<()> {
+        use hyper::service::{make_service_fn, service_fn};
+        use hyper::{Body, Response, Server};
+
+        let metrics = self;
+        let make_svc = make_service_fn(move |_conn| {
+            let metrics = Arc::clone(&metrics);
+            async move {
+                Ok::<_, Infallible>(service_fn(move |_req| {
+                    let metrics = Arc::clone(&metrics);
+                    async move { Ok::<_, Infallible>(Response::new(Body::from(metrics.render()))) }
+                }))
+            }
+        });
+
+        Server::bind(&addr)
+            .serve(make_svc)
+            .await
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e))
+    }
+}
+
+/// Alias so `?` works cleanly against `prometheus::Error` inside [`Metrics::new`].
+type RegistryResult<T> = Result<T, prometheus::Error>;
@@ -4,12 +4,48 @@
 //! It manages concurrency using Tokio streams to ensure high-throughput testing.
 
 use crate::{
-    evaluator::Evaluator, strategy::Strategy, target::Target, AttackResult, RedOxideResult,
+    conversation::Session,
+    evaluator::Evaluator,
+    metrics::Metrics,
+    strategy::{ConversationalStrategy, Strategy},
+    target::Target,
+    AttackResult, RedOxideResult,
 };
+#[cfg(test)]
+use async_trait::async_trait;
 use colored::*;
 use futures::{stream, StreamExt};
+use rand::Rng;
 use std::io::{self, Write};
+use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::Instrument;
+
+/// Retry and timeout policy applied to every prompt request issued by the [`Runner`].
+///
+/// A transient 429 or network blip shouldn't silently shrink the result set of a security
+/// scan, so failed requests are retried with exponential backoff before being recorded as a
+/// genuine failure.
+#[derive(Debug, Clone)]
+pub struct RunnerConfig {
+    /// Maximum number of retries after the initial attempt.
+    pub max_retries: u32,
+    /// Base delay used for the exponential backoff (`base_delay * 2^attempt`, plus jitter).
+    pub base_delay: Duration,
+    /// How long to wait for a single request before treating it as failed.
+    pub request_timeout: Duration,
+}
+
+impl Default for RunnerConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            request_timeout: Duration::from_secs(30),
+        }
+    }
+}
 
 /// Orchestrates the execution of red teaming scans.
 pub struct Runner {
@@ -17,21 +53,59 @@ pub struct Runner {
     concurrency: usize,
     /// Whether to print real-time logs to stdout.
     verbose: bool,
+    /// Retry/backoff/timeout policy for individual prompt requests.
+    config: RunnerConfig,
+    /// Prometheus metrics, if enabled via [`Runner::with_metrics`].
+    metrics: Option<Arc<Metrics>>,
 }
 
 impl Runner {
-    /// Creates a new Runner instance.
+    /// Creates a new Runner instance with the default retry policy.
     ///
     /// # Arguments
     /// * `concurrency` - Max number of parallel futures (e.g., 5 or 10).
     /// * `verbose` - If true, prints colorful logs and prompts to stdout.
     pub fn new(concurrency: usize, verbose: bool) -> Self {
+        Self::with_config(concurrency, verbose, RunnerConfig::default())
+    }
+
+    /// Creates a new Runner instance with a custom retry policy.
+    ///
+    /// # Arguments
+    /// * `concurrency` - Max number of parallel futures (e.g., 5 or 10).
+    /// * `verbose` - If true, prints colorful logs and prompts to stdout.
+    /// * `config` - Retry/backoff/timeout policy for individual prompt requests.
+    pub fn with_config(concurrency: usize, verbose: bool, config: RunnerConfig) -> Self {
         Self {
             concurrency,
             verbose,
+            config,
+            metrics: None,
         }
     }
 
+    /// Enables Prometheus metrics, optionally serving them over `/metrics` on `metrics_addr`.
+    ///
+    /// Counters for prompts sent, attacks succeeded, and requests failed, plus a latency
+    /// histogram, are all labeled by strategy name and target model. Pass `None` for
+    /// `metrics_addr` to collect metrics without exposing an HTTP endpoint; the collected
+    /// values are then not readable from outside the `Runner`.
+    pub fn with_metrics(mut self, metrics_addr: Option<SocketAddr>) -> RedOxideResult<Self> {
+        let metrics = Arc::new(Metrics::new()?);
+
+        if let Some(addr) = metrics_addr {
+            let serving = Arc::clone(&metrics);
+            tokio::spawn(async move {
+                if let Err(e) = serving.serve(addr).await {
+                    tracing::warn!(error = %e, "metrics server exited");
+                }
+            });
+        }
+
+        self.metrics = Some(metrics);
+        Ok(self)
+    }
+
     /// Executes the full scan pipeline.
     ///
     /// This method:
@@ -73,53 +147,97 @@ impl Runner {
                 let evaluator = Arc::clone(&evaluator);
                 let strategy_name = strategy.name();
                 let verbose = self.verbose;
+                let config = self.config.clone();
+                let metrics = self.metrics.clone();
+                let model = target.model_name();
+
+                let span = tracing::info_span!(
+                    "prompt_attempt",
+                    strategy = %strategy_name,
+                    model = %model
+                );
 
                 async move {
-                    // Send request using safe reference conversion
-                    let response_result = target.as_ref().send_prompt(&prompt).await;
+                    if let Some(m) = &metrics {
+                        m.record_prompt_sent(&strategy_name, &model);
+                    }
 
-                    let response = match response_result {
-                        Ok(r) => r,
+                    let started = Instant::now();
+                    let attempt = send_with_retry_stream(
+                        target.as_ref(),
+                        &prompt,
+                        &config,
+                        verbose,
+                        evaluator.as_ref(),
+                    )
+                    .await;
+                    let elapsed = started.elapsed().as_secs_f64();
+                    if let Some(m) = &metrics {
+                        m.observe_latency(&strategy_name, &model, elapsed);
+                    }
+
+                    let (response, success) = match attempt {
+                        Ok(outcome) => outcome,
                         Err(e) => {
-                            if verbose {
-                                eprintln!("Request failed: {}", e);
+                            tracing::warn!(
+                                error = %e,
+                                retries = config.max_retries,
+                                "request failed after retries"
+                            );
+                            if let Some(m) = &metrics {
+                                m.record_request_failed(&strategy_name, &model);
                             }
-                            return None;
+                            return AttackResult {
+                                prompt,
+                                response: format!(
+                                    "ERROR: failed after {} retries: {}",
+                                    config.max_retries, e
+                                ),
+                                success: false,
+                                strategy_name,
+                                transcript: Vec::new(),
+                                tool_calls: Vec::new(),
+                            };
                         }
                     };
 
-                    // Evaluate the response
-                    let success = evaluator
-                        .as_ref()
-                        .evaluate(&prompt, &response)
-                        .await
-                        .unwrap_or(false);
+                    tracing::info!(success, "prompt attempt completed");
 
-                    if verbose {
-                        if success {
+                    // Structured tracing events (above) and the Prometheus counters enabled via
+                    // `Runner::with_metrics` are for machine consumers; the `verbose`-gated
+                    // println!/print!("." ) calls below
+                    // are kept as-is, deliberately, for interactive CLI use, where a human
+                    // wants a readable progress indicator instead of a JSON log line.
+                    if success {
+                        if let Some(m) = &metrics {
+                            m.record_attack_succeeded(&strategy_name, &model);
+                        }
+                        if verbose {
                             println!(
                                 "\n[{}] {}",
                                 "VULNERABLE".red().bold(),
                                 prompt.chars().take(50).collect::<String>()
                             );
-                        } else {
-                            // Progress dot for safe responses to avoid clutter
-                            print!(".");
-                            io::stdout().flush().ok();
                         }
+                    } else if verbose {
+                        // Progress dot for safe responses to avoid clutter
+                        print!(".");
+                        io::stdout().flush().ok();
                     }
 
-                    Some(AttackResult {
+                    AttackResult {
                         prompt,
                         response,
                         success,
                         strategy_name,
-                    })
+                        transcript: Vec::new(),
+                        tool_calls: Vec::new(),
+                    }
                 }
+                .instrument(span)
             })
             // Use buffer_unordered to run futures in parallel
             .buffer_unordered(self.concurrency)
-            .filter_map(|x| async { x })
             .collect::<Vec<_>>()
             .await;
 
@@ -129,4 +247,347 @@ impl Runner {
 
         Ok(results)
     }
+
+    /// Drives a [`ConversationalStrategy`] turn by turn, instead of running a precomputed list
+    /// of prompts.
+    ///
+    /// Turns are sent through a [`Session`], so the target sees the entire conversation built
+    /// up so far (via [`Session::send_turn`]) rather than a fresh, history-less prompt each
+    /// time. At each step, `strategy.next_turn` is asked to craft the next prompt from the
+    /// conversation so far; the result is sent (with the same retry/timeout policy and
+    /// metrics as [`Runner::run`]) and judged by `evaluator`. The loop stops as soon as the
+    /// evaluator flags success, the strategy returns `None`, or `max_turns` is reached.
+    pub async fn run_conversational(
+        &self,
+        target: Arc<dyn Target>,
+        strategy: Arc<dyn ConversationalStrategy>,
+        evaluator: Arc<dyn Evaluator>,
+        max_turns: usize,
+    ) -> RedOxideResult<AttackResult> {
+        let strategy_name = strategy.name();
+        let model = target.model_name();
+        let mut session = Session::new(Arc::clone(&target));
+        let mut transcript: Vec<(String, String)> = Vec::new();
+        // The attack's starting point (the opening, benign prompt), not the last escalated
+        // one — this matches how single-shot strategies report the prompt that triggered the
+        // scan, and lets a report be grouped/read by objective rather than by final phrasing.
+        let mut seed_prompt: Option<String> = None;
+        let mut last_response = String::new();
+        let mut success = false;
+
+        for _ in 0..max_turns {
+            let Some(prompt) = strategy.next_turn(&transcript).await else {
+                break;
+            };
+            if seed_prompt.is_none() {
+                seed_prompt = Some(prompt.clone());
+            }
+
+            if let Some(m) = &self.metrics {
+                m.record_prompt_sent(&strategy_name, &model);
+            }
+
+            let started = Instant::now();
+            let attempt =
+                send_turn_with_retry(&mut session, &prompt, &self.config, self.verbose).await;
+            let elapsed = started.elapsed().as_secs_f64();
+            if let Some(m) = &self.metrics {
+                m.observe_latency(&strategy_name, &model, elapsed);
+            }
+
+            let response = match attempt {
+                Ok(r) => r,
+                Err(e) => {
+                    tracing::warn!(error = %e, "conversational turn failed after retries");
+                    if let Some(m) = &self.metrics {
+                        m.record_request_failed(&strategy_name, &model);
+                    }
+                    last_response = format!(
+                        "ERROR: failed after {} retries: {}",
+                        self.config.max_retries, e
+                    );
+                    break;
+                }
+            };
+
+            last_response = response.clone();
+            transcript.push((prompt.clone(), response.clone()));
+
+            success = evaluator
+                .evaluate(&prompt, &response)
+                .await
+                .unwrap_or(false);
+
+            if success {
+                if let Some(m) = &self.metrics {
+                    m.record_attack_succeeded(&strategy_name, &model);
+                }
+                if self.verbose {
+                    println!(
+                        "\n[{}] {}",
+                        "VULNERABLE".red().bold(),
+                        prompt.chars().take(50).collect::<String>()
+                    );
+                }
+                break;
+            } else if self.verbose {
+                print!(".");
+                io::stdout().flush().ok();
+            }
+        }
+
+        Ok(AttackResult {
+            prompt: seed_prompt.unwrap_or_default(),
+            response: last_response,
+            success,
+            strategy_name,
+            transcript,
+            tool_calls: Vec::new(),
+        })
+    }
+}
+
+/// Sends `prompt` as the next turn of `session`, retrying failed attempts with exponential
+/// backoff.
+///
+/// Each attempt is bounded by `config.request_timeout`. On failure, the delay before the
+/// next attempt is taken from a `Retry-After` hint in the error message if one is present
+/// (since the `Target` trait abstracts away raw HTTP responses, this is the only place that
+/// hint can still surface); otherwise it falls back to `base_delay * 2^attempt` plus jitter.
+///
+/// [`Session::send_turn`] appends the user turn to history before contacting the target, so a
+/// failed attempt rolls that turn back via [`Session::truncate_history`] before retrying —
+/// otherwise a retried prompt would appear twice in the transcript sent to the target.
+async fn send_turn_with_retry(
+    session: &mut Session,
+    prompt: &str,
+    config: &RunnerConfig,
+    verbose: bool,
+) -> RedOxideResult<String> {
+    let mut attempt = 0;
+    let base_len = session.history().len();
+
+    loop {
+        let attempt_result = match tokio::time::timeout(
+            config.request_timeout,
+            session.send_turn(prompt),
+        )
+        .await
+        {
+            Ok(result) => result,
+            Err(_) => Err(anyhow::anyhow!(
+                "request timed out after {:?}",
+                config.request_timeout
+            )),
+        };
+
+        match attempt_result {
+            Ok(response) => return Ok(response),
+            Err(e) if attempt < config.max_retries => {
+                session.truncate_history(base_len);
+
+                let delay = retry_after_delay(&e.to_string()).unwrap_or_else(|| {
+                    let backoff = config.base_delay * 2u32.pow(attempt);
+                    let jitter = Duration::from_millis(rand::thread_rng().gen_range(0..250));
+                    backoff + jitter
+                });
+
+                tracing::debug!(
+                    error = %e,
+                    delay = ?delay,
+                    attempt = attempt + 1,
+                    max_retries = config.max_retries,
+                    "conversational turn failed, retrying"
+                );
+                if verbose {
+                    eprintln!(
+                        "Request failed ({}), retrying in {:?} (attempt {}/{})",
+                        e,
+                        delay,
+                        attempt + 1,
+                        config.max_retries
+                    );
+                }
+
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+            Err(e) => {
+                session.truncate_history(base_len);
+                return Err(e);
+            }
+        }
+    }
+}
+
+/// Sends `prompt` to `target` via its streaming API, checking `evaluator` after every chunk
+/// so a scan doesn't have to wait for the full completion once a response has already been
+/// confirmed unsafe — this is what makes [`Target::send_prompt_stream`] load-bearing instead
+/// of a capability nothing consumes.
+///
+/// Retry/backoff/timeout behaves exactly like [`send_turn_with_retry`] (including the
+/// `Retry-After` hint lookup); targets that don't override `send_prompt_stream` still work
+/// correctly, just evaluating their single chunk once the whole response is in.
+async fn send_with_retry_stream(
+    target: &dyn Target,
+    prompt: &str,
+    config: &RunnerConfig,
+    verbose: bool,
+    evaluator: &dyn Evaluator,
+) -> RedOxideResult<(String, bool)> {
+    let mut attempt = 0;
+
+    loop {
+        let attempt_result = match tokio::time::timeout(config.request_timeout, async {
+            let mut chunks = target.send_prompt_stream(prompt).await?;
+            let mut accumulated = String::new();
+
+            while let Some(chunk) = chunks.next().await {
+                accumulated.push_str(&chunk?);
+
+                if evaluator
+                    .evaluate(prompt, &accumulated)
+                    .await
+                    .unwrap_or(false)
+                {
+                    return Ok((accumulated, true));
+                }
+            }
+
+            Ok((accumulated, false))
+        })
+        .await
+        {
+            Ok(result) => result,
+            Err(_) => Err(anyhow::anyhow!(
+                "request timed out after {:?}",
+                config.request_timeout
+            )),
+        };
+
+        match attempt_result {
+            Ok(outcome) => return Ok(outcome),
+            Err(e) if attempt < config.max_retries => {
+                let delay = retry_after_delay(&e.to_string()).unwrap_or_else(|| {
+                    let backoff = config.base_delay * 2u32.pow(attempt);
+                    let jitter = Duration::from_millis(rand::thread_rng().gen_range(0..250));
+                    backoff + jitter
+                });
+
+                tracing::debug!(
+                    error = %e,
+                    delay = ?delay,
+                    attempt = attempt + 1,
+                    max_retries = config.max_retries,
+                    "streaming request failed, retrying"
+                );
+                if verbose {
+                    eprintln!(
+                        "Request failed ({}), retrying in {:?} (attempt {}/{})",
+                        e,
+                        delay,
+                        attempt + 1,
+                        config.max_retries
+                    );
+                }
+
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Looks for a `Retry-After: <seconds>` style hint inside an error message.
+fn retry_after_delay(error_message: &str) -> Option<Duration> {
+    let lower = error_message.to_lowercase();
+    let after = lower.split("retry-after").nth(1)?;
+    let digits: String = after
+        .chars()
+        .skip_while(|c| !c.is_ascii_digit())
+        .take_while(|c| c.is_ascii_digit())
+        .collect();
+
+    digits.parse::<u64>().ok().map(Duration::from_secs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_after_delay_parses_hint() {
+        let delay = retry_after_delay("429 Too Many Requests, Retry-After: 7");
+        assert_eq!(delay, Some(Duration::from_secs(7)));
+    }
+
+    #[test]
+    fn test_retry_after_delay_absent() {
+        assert_eq!(retry_after_delay("connection reset by peer"), None);
+    }
+
+    struct ChunkedTarget {
+        chunks: Vec<&'static str>,
+    }
+
+    #[async_trait]
+    impl Target for ChunkedTarget {
+        async fn send_prompt(&self, _prompt: &str) -> RedOxideResult<String> {
+            Ok(self.chunks.concat())
+        }
+
+        async fn send_prompt_stream(
+            &self,
+            _prompt: &str,
+        ) -> RedOxideResult<futures::stream::BoxStream<'_, RedOxideResult<String>>> {
+            Ok(stream::iter(self.chunks.iter().map(|c| Ok(c.to_string()))).boxed())
+        }
+    }
+
+    struct ContainsEvaluator {
+        needle: &'static str,
+    }
+
+    #[async_trait]
+    impl Evaluator for ContainsEvaluator {
+        async fn evaluate(&self, _prompt: &str, response: &str) -> RedOxideResult<bool> {
+            Ok(response.contains(self.needle))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_send_with_retry_stream_aborts_as_soon_as_evaluator_flags_success() {
+        let target = ChunkedTarget {
+            chunks: vec!["Sure, ", "here is ", "the BOOM ", "you should never see this"],
+        };
+        let evaluator = ContainsEvaluator { needle: "BOOM" };
+        let config = RunnerConfig::default();
+
+        let (response, success) =
+            send_with_retry_stream(&target, "prompt", &config, false, &evaluator)
+                .await
+                .unwrap();
+
+        assert!(success);
+        assert_eq!(response, "Sure, here is the BOOM ");
+        assert!(!response.contains("never see this"));
+    }
+
+    #[tokio::test]
+    async fn test_send_with_retry_stream_returns_full_response_when_never_flagged() {
+        let target = ChunkedTarget {
+            chunks: vec!["all ", "clear"],
+        };
+        let evaluator = ContainsEvaluator { needle: "BOOM" };
+        let config = RunnerConfig::default();
+
+        let (response, success) =
+            send_with_retry_stream(&target, "prompt", &config, false, &evaluator)
+                .await
+                .unwrap();
+
+        assert!(!success);
+        assert_eq!(response, "all clear");
+    }
 }
@@ -0,0 +1,233 @@
+//! A long-lived server mode that accepts evaluation jobs over a socket (TCP or UNIX domain),
+//! so CI systems and other tools can submit prompts without spawning the binary per request.
+
+use crate::evaluator::{Evaluator, KeywordEvaluator, LLMJudge};
+use crate::runner::Runner;
+use crate::strategy::{JailbreakStrategy, PayloadSplittingStrategy, ResearchStrategy, Strategy};
+use crate::target::Target;
+use crate::{AttackResult, RedOxideResult};
+use serde::Deserialize;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncWrite, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, UnixListener};
+use tokio::task::JoinSet;
+
+/// Where to listen for incoming evaluation jobs.
+#[derive(Debug, Clone)]
+pub enum SocketSpec {
+    /// A TCP socket, parsed from `inet:HOST:PORT`.
+    Tcp(SocketAddr),
+    /// A UNIX domain socket, parsed from `unix:PATH`.
+    Unix(PathBuf),
+}
+
+impl SocketSpec {
+    /// Parses a socket spec of the form `inet:HOST:PORT` or `unix:PATH`.
+    pub fn parse(spec: &str) -> RedOxideResult<Self> {
+        if let Some(rest) = spec.strip_prefix("inet:") {
+            let addr: SocketAddr = rest
+                .parse()
+                .map_err(|e| anyhow::anyhow!("invalid inet socket spec {:?}: {}", spec, e))?;
+            Ok(SocketSpec::Tcp(addr))
+        } else if let Some(path) = spec.strip_prefix("unix:") {
+            Ok(SocketSpec::Unix(PathBuf::from(path)))
+        } else {
+            Err(anyhow::anyhow!(
+                "socket spec must start with \"inet:\" or \"unix:\", got {:?}",
+                spec
+            ))
+        }
+    }
+}
+
+/// A single evaluation job submitted over the socket: one prompt, plus optional strategy and
+/// evaluator selection.
+#[derive(Debug, Deserialize)]
+struct ServeJob {
+    prompt: String,
+    #[serde(default)]
+    strategy: Option<String>,
+    #[serde(default)]
+    evaluator: Option<String>,
+}
+
+/// Runs the server loop until Ctrl+C (SIGINT) or, on UNIX, SIGTERM is received. Already
+/// in-flight connections are allowed to finish before the function returns.
+pub async fn serve(
+    spec: SocketSpec,
+    target: Arc<dyn Target>,
+    api_key: String,
+) -> RedOxideResult<()> {
+    let mut tasks = JoinSet::new();
+
+    match spec {
+        SocketSpec::Tcp(addr) => {
+            let listener = TcpListener::bind(addr).await?;
+            tracing::info!(%addr, "listening for evaluation jobs (tcp)");
+
+            loop {
+                tokio::select! {
+                    accepted = listener.accept() => {
+                        let (stream, peer) = accepted?;
+                        tracing::debug!(%peer, "accepted tcp connection");
+                        let target = Arc::clone(&target);
+                        let api_key = api_key.clone();
+                        tasks.spawn(async move {
+                            if let Err(e) = handle_connection(stream, target, api_key).await {
+                                tracing::warn!(error = %e, "connection handler failed");
+                            }
+                        });
+                    }
+                    _ = shutdown_signal() => {
+                        tracing::info!("shutdown signal received, closing tcp listener");
+                        break;
+                    }
+                }
+            }
+        }
+        SocketSpec::Unix(path) => {
+            if path.exists() {
+                std::fs::remove_file(&path)?;
+            }
+            let listener = UnixListener::bind(&path)?;
+            tracing::info!(path = %path.display(), "listening for evaluation jobs (unix)");
+
+            loop {
+                tokio::select! {
+                    accepted = listener.accept() => {
+                        let (stream, _) = accepted?;
+                        tracing::debug!("accepted unix connection");
+                        let target = Arc::clone(&target);
+                        let api_key = api_key.clone();
+                        tasks.spawn(async move {
+                            if let Err(e) = handle_connection(stream, target, api_key).await {
+                                tracing::warn!(error = %e, "connection handler failed");
+                            }
+                        });
+                    }
+                    _ = shutdown_signal() => {
+                        tracing::info!("shutdown signal received, closing unix listener");
+                        break;
+                    }
+                }
+            }
+
+            let _ = std::fs::remove_file(&path);
+        }
+    }
+
+    tracing::info!(remaining = tasks.len(), "draining in-flight connections");
+    while tasks.join_next().await.is_some() {}
+
+    Ok(())
+}
+
+/// Reads newline-delimited [`ServeJob`] JSON objects from `stream` and writes back the
+/// results (or an `{"error": ...}` object) as newline-delimited JSON.
+async fn handle_connection<S>(
+    stream: S,
+    target: Arc<dyn Target>,
+    api_key: String,
+) -> RedOxideResult<()>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let (reader, mut writer) = tokio::io::split(stream);
+    let mut lines = BufReader::new(reader).lines();
+
+    while let Some(line) = lines.next_line().await? {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let outcome = match serde_json::from_str::<ServeJob>(&line) {
+            Ok(job) => run_job(job, Arc::clone(&target), &api_key).await,
+            Err(e) => Err(anyhow::anyhow!("invalid job JSON: {}", e)),
+        };
+
+        let payload = match outcome {
+            Ok(results) => serde_json::to_string(&results)?,
+            Err(e) => serde_json::to_string(&serde_json::json!({ "error": e.to_string() }))?,
+        };
+
+        writer.write_all(payload.as_bytes()).await?;
+        writer.write_all(b"\n").await?;
+        writer.flush().await?;
+    }
+
+    Ok(())
+}
+
+/// Builds a single-prompt strategy/evaluator pair from `job` and runs it through the `Runner`.
+async fn run_job(
+    job: ServeJob,
+    target: Arc<dyn Target>,
+    api_key: &str,
+) -> RedOxideResult<Vec<AttackResult>> {
+    let strategy: Arc<dyn Strategy> = match job.strategy.as_deref() {
+        Some("splitting") => Arc::new(PayloadSplittingStrategy::new(vec![job.prompt.clone()])),
+        Some("research") => Arc::new(ResearchStrategy::new(vec![job.prompt.clone()])),
+        _ => Arc::new(JailbreakStrategy::new(vec![job.prompt.clone()])),
+    };
+
+    let evaluator: Arc<dyn Evaluator> = match job.evaluator.as_deref() {
+        Some("judge") => Arc::new(LLMJudge::new(api_key.to_string(), "gpt-4".to_string())),
+        _ => Arc::new(KeywordEvaluator::default()),
+    };
+
+    let runner = Runner::new(1, false);
+    runner.run(target, strategy, evaluator).await
+}
+
+/// Resolves once Ctrl+C (SIGINT) or, on UNIX, SIGTERM is received.
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        let _ = tokio::signal::ctrl_c().await;
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        use tokio::signal::unix::{signal, SignalKind};
+        let mut sig =
+            signal(SignalKind::terminate()).expect("failed to install SIGTERM handler");
+        sig.recv().await;
+    };
+
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {}
+        _ = terminate => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_socket_spec_parses_inet() {
+        let spec = SocketSpec::parse("inet:127.0.0.1:9000").unwrap();
+        match spec {
+            SocketSpec::Tcp(addr) => assert_eq!(addr.port(), 9000),
+            SocketSpec::Unix(_) => panic!("expected Tcp variant"),
+        }
+    }
+
+    #[test]
+    fn test_socket_spec_parses_unix() {
+        let spec = SocketSpec::parse("unix:/tmp/redoxide.sock").unwrap();
+        match spec {
+            SocketSpec::Unix(path) => assert_eq!(path, PathBuf::from("/tmp/redoxide.sock")),
+            SocketSpec::Tcp(_) => panic!("expected Unix variant"),
+        }
+    }
+
+    #[test]
+    fn test_socket_spec_rejects_unknown_scheme() {
+        assert!(SocketSpec::parse("http://example.com").is_err());
+    }
+}
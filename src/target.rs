@@ -3,18 +3,44 @@
 //! The [`Target`] trait abstracts away the differences between various API providers
 //! (OpenAI, Anthropic, Google, Meta), allowing strategies to be run against any supported backend.
 
+use crate::conversation::{ChatMessage, Role};
 use crate::RedOxideResult;
 use async_openai::{
     config::OpenAIConfig,
     types::{
-        ChatCompletionRequestMessage, ChatCompletionRequestUserMessageArgs,
-        CreateChatCompletionRequestArgs,
+        ChatCompletionRequestAssistantMessageArgs, ChatCompletionRequestMessage,
+        ChatCompletionRequestUserMessageArgs, ChatCompletionTool, ChatCompletionToolType,
+        CreateChatCompletionRequestArgs, FunctionObject,
     },
     Client,
 };
 use async_trait::async_trait;
+use eventsource_stream::Eventsource;
+use futures::stream::{self, BoxStream, StreamExt};
 use reqwest::Client as HttpClient;
+use serde::{Deserialize, Serialize};
 use serde_json::json;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Describes a tool/function the target may be offered to call, mirroring the
+/// OpenAI/Anthropic tool schema (name, description, JSON-schema parameters).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolSpec {
+    pub name: String,
+    pub description: String,
+    pub parameters: serde_json::Value,
+}
+
+/// A tool invocation requested by the target, normalized across providers.
+///
+/// OpenAI and Anthropic return tool calls in different JSON shapes; every [`Target`]
+/// implementation is responsible for mapping its provider's shape into this one.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolCall {
+    pub name: String,
+    pub arguments: serde_json::Value,
+}
 
 /// A trait representing a target system (LLM) to be tested.
 ///
@@ -29,6 +55,74 @@ pub trait Target: Send + Sync {
     /// # Returns
     /// A `Result` containing the model's text response or an error if the network request failed.
     async fn send_prompt(&self, prompt: &str) -> RedOxideResult<String>;
+
+    /// Sends a prompt and streams back the response as incremental text chunks.
+    ///
+    /// This lets callers (e.g. [`crate::runner::Runner`]) react to partial output, such as
+    /// aborting a scan as soon as unsafe content starts to appear instead of waiting for the
+    /// full completion.
+    ///
+    /// The default implementation just wraps [`Target::send_prompt`] in a single-item stream,
+    /// so targets that don't override this still behave correctly, just without incrementality.
+    async fn send_prompt_stream(
+        &self,
+        prompt: &str,
+    ) -> RedOxideResult<BoxStream<'_, RedOxideResult<String>>> {
+        let chunk = self.send_prompt(prompt).await;
+        Ok(stream::once(async move { chunk }).boxed())
+    }
+
+    /// Sends an entire conversation history and returns the next assistant reply.
+    ///
+    /// This is what powers multi-turn attacks (e.g. [`crate::conversation::Session`] and
+    /// Crescendo-style strategies): the target sees every prior turn instead of a single
+    /// isolated prompt, so it can be steered gradually.
+    ///
+    /// The default implementation flattens the history into one framed prompt and falls
+    /// back to [`Target::send_prompt`], so targets that don't override this still work,
+    /// just without native message-array support.
+    async fn send_conversation(&self, history: &[ChatMessage]) -> RedOxideResult<String> {
+        let framed = history
+            .iter()
+            .map(|m| {
+                let speaker = match m.role {
+                    Role::User => "User",
+                    Role::Assistant => "Assistant",
+                };
+                format!("{}: {}", speaker, m.content)
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        self.send_prompt(&framed).await
+    }
+
+    /// Sends a prompt alongside a set of tools/functions the target may choose to call.
+    ///
+    /// Returns the model's text reply together with any tool calls it requested, normalized
+    /// into [`ToolCall`] regardless of the provider's native JSON shape. This is what lets a
+    /// [`crate::evaluator::FunctionCallEvaluator`] judge whether a model can be coaxed into
+    /// invoking a sensitive tool.
+    ///
+    /// The default implementation ignores `tools` and falls back to [`Target::send_prompt`],
+    /// so targets that don't override this still work, they just never report tool calls.
+    async fn send_prompt_with_tools(
+        &self,
+        prompt: &str,
+        tools: &[ToolSpec],
+    ) -> RedOxideResult<(String, Vec<ToolCall>)> {
+        let _ = tools;
+        let text = self.send_prompt(prompt).await?;
+        Ok((text, Vec::new()))
+    }
+
+    /// The target's model identifier, used to label metrics and reports.
+    ///
+    /// The default implementation returns `"unknown"`; implementations should override this
+    /// with whatever model name they were constructed with.
+    fn model_name(&self) -> String {
+        "unknown".to_string()
+    }
 }
 
 /// Implementation for OpenAI's Chat Completion API (e.g., GPT-3.5, GPT-4).
@@ -50,10 +144,31 @@ impl OpenAITarget {
         let client = Client::with_config(config);
         Self { client, model }
     }
+
+    /// Creates a new OpenAI target pointed at a custom API base URL.
+    ///
+    /// Useful for Azure OpenAI deployments or any other gateway that mirrors the OpenAI
+    /// Chat Completions API but isn't reachable at the default `api.openai.com` host.
+    ///
+    /// # Arguments
+    /// * `api_key` - API key for the endpoint.
+    /// * `model` - The model identifier.
+    /// * `api_base` - The base URL of the API (e.g., `https://my-proxy.example.com/v1`).
+    pub fn with_api_base(api_key: String, model: String, api_base: String) -> Self {
+        let config = OpenAIConfig::new()
+            .with_api_key(api_key)
+            .with_api_base(api_base);
+        let client = Client::with_config(config);
+        Self { client, model }
+    }
 }
 
 #[async_trait]
 impl Target for OpenAITarget {
+    fn model_name(&self) -> String {
+        self.model.clone()
+    }
+
     async fn send_prompt(&self, prompt: &str) -> RedOxideResult<String> {
         let user_msg_struct = ChatCompletionRequestUserMessageArgs::default()
             .content(prompt)
@@ -74,6 +189,219 @@ impl Target for OpenAITarget {
             .and_then(|c| c.message.content.clone())
             .unwrap_or_default())
     }
+
+    async fn send_conversation(&self, history: &[ChatMessage]) -> RedOxideResult<String> {
+        let mut messages = Vec::with_capacity(history.len());
+        for turn in history {
+            let message = match turn.role {
+                Role::User => ChatCompletionRequestMessage::User(
+                    ChatCompletionRequestUserMessageArgs::default()
+                        .content(turn.content.clone())
+                        .build()?,
+                ),
+                Role::Assistant => ChatCompletionRequestMessage::Assistant(
+                    ChatCompletionRequestAssistantMessageArgs::default()
+                        .content(turn.content.clone())
+                        .build()?,
+                ),
+            };
+            messages.push(message);
+        }
+
+        let request = CreateChatCompletionRequestArgs::default()
+            .model(&self.model)
+            .messages(messages)
+            .build()?;
+
+        let response = self.client.chat().create(request).await?;
+
+        Ok(response
+            .choices
+            .first()
+            .and_then(|c| c.message.content.clone())
+            .unwrap_or_default())
+    }
+
+    async fn send_prompt_with_tools(
+        &self,
+        prompt: &str,
+        tools: &[ToolSpec],
+    ) -> RedOxideResult<(String, Vec<ToolCall>)> {
+        let user_msg_struct = ChatCompletionRequestUserMessageArgs::default()
+            .content(prompt)
+            .build()?;
+
+        let message = ChatCompletionRequestMessage::User(user_msg_struct);
+
+        let tool_defs: Vec<ChatCompletionTool> = tools
+            .iter()
+            .map(|t| ChatCompletionTool {
+                r#type: ChatCompletionToolType::Function,
+                function: FunctionObject {
+                    name: t.name.clone(),
+                    description: Some(t.description.clone()),
+                    parameters: Some(t.parameters.clone()),
+                    strict: None,
+                },
+            })
+            .collect();
+
+        let request = CreateChatCompletionRequestArgs::default()
+            .model(&self.model)
+            .messages(vec![message])
+            .tools(tool_defs)
+            .build()?;
+
+        let response = self.client.chat().create(request).await?;
+        let choice = response.choices.into_iter().next();
+
+        let text = choice
+            .as_ref()
+            .and_then(|c| c.message.content.clone())
+            .unwrap_or_default();
+
+        let tool_calls = choice
+            .and_then(|c| c.message.tool_calls)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|tc| ToolCall {
+                name: tc.function.name,
+                arguments: serde_json::from_str(&tc.function.arguments)
+                    .unwrap_or(serde_json::Value::Null),
+            })
+            .collect();
+
+        Ok((text, tool_calls))
+    }
+
+    async fn send_prompt_stream(
+        &self,
+        prompt: &str,
+    ) -> RedOxideResult<BoxStream<'_, RedOxideResult<String>>> {
+        let user_msg_struct = ChatCompletionRequestUserMessageArgs::default()
+            .content(prompt)
+            .build()?;
+
+        let message = ChatCompletionRequestMessage::User(user_msg_struct);
+
+        let request = CreateChatCompletionRequestArgs::default()
+            .model(&self.model)
+            .messages(vec![message])
+            .stream(true)
+            .build()?;
+
+        let openai_stream = self.client.chat().create_stream(request).await?;
+
+        Ok(openai_stream
+            .map(|event| {
+                let delta = event?
+                    .choices
+                    .first()
+                    .and_then(|c| c.delta.content.clone())
+                    .unwrap_or_default();
+                Ok(delta)
+            })
+            .boxed())
+    }
+}
+
+/// Builds an error from a non-2xx HTTP `res`, folding in the `Retry-After` header (if present)
+/// so `crate::runner::retry_after_delay` can pull a real backoff hint out of the error message
+/// instead of the [`Runner`](crate::runner::Runner)'s blind exponential backoff. Shared by
+/// every [`Target`] impl that talks to its provider over raw HTTP, so the hint isn't just a
+/// one-off [`GenericOpenAITarget`] behavior.
+async fn http_error(context: &str, res: reqwest::Response) -> anyhow::Error {
+    let retry_after = res
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    let body = res.text().await.unwrap_or_default();
+
+    match retry_after {
+        Some(seconds) => anyhow::anyhow!("{} (Retry-After: {}): {}", context, seconds, body),
+        None => anyhow::anyhow!("{}: {}", context, body),
+    }
+}
+
+/// Implementation for any OpenAI-compatible Chat Completions endpoint.
+///
+/// Many self-hosted and proxied deployments (LocalAI, vLLM, LM Studio, Groq, Together, etc.)
+/// speak the same request/response shape as OpenAI's `/chat/completions` but live at a
+/// different host and sometimes require extra headers (e.g. a gateway API key) that
+/// [`OpenAITarget`] has no way to attach. This target talks to them directly over HTTP
+/// instead of going through `async-openai`'s client.
+pub struct GenericOpenAITarget {
+    client: HttpClient,
+    base_url: String,
+    api_key: String,
+    model: String,
+    extra_headers: std::collections::HashMap<String, String>,
+}
+
+impl GenericOpenAITarget {
+    /// Creates a new target against an OpenAI-compatible `base_url`.
+    ///
+    /// # Arguments
+    /// * `base_url` - The API root, e.g. `http://localhost:8080/v1` (no trailing slash).
+    /// * `api_key` - Sent as a `Bearer` token; pass an empty string for endpoints that don't
+    ///   require one.
+    /// * `model` - The model identifier as understood by the endpoint.
+    /// * `extra_headers` - Any additional headers the gateway requires (e.g. `api-key`).
+    pub fn new(
+        base_url: String,
+        api_key: String,
+        model: String,
+        extra_headers: std::collections::HashMap<String, String>,
+    ) -> Self {
+        Self {
+            client: HttpClient::new(),
+            base_url,
+            api_key,
+            model,
+            extra_headers,
+        }
+    }
+}
+
+#[async_trait]
+impl Target for GenericOpenAITarget {
+    fn model_name(&self) -> String {
+        self.model.clone()
+    }
+
+    async fn send_prompt(&self, prompt: &str) -> RedOxideResult<String> {
+        let url = format!("{}/chat/completions", self.base_url);
+
+        let body = json!({
+            "model": self.model,
+            "messages": [{ "role": "user", "content": prompt }]
+        });
+
+        let mut req = self.client.post(&url).json(&body);
+
+        if !self.api_key.is_empty() {
+            req = req.bearer_auth(&self.api_key);
+        }
+        for (name, value) in &self.extra_headers {
+            req = req.header(name, value);
+        }
+
+        let res = req.send().await?;
+
+        if !res.status().is_success() {
+            return Err(http_error("OpenAI-compatible endpoint error", res).await);
+        }
+
+        let json_resp: serde_json::Value = res.json().await?;
+
+        let text = json_resp["choices"][0]["message"]["content"]
+            .as_str()
+            .unwrap_or("")
+            .to_string();
+
+        Ok(text)
+    }
 }
 
 /// Implementation for Anthropic's Messages API (e.g., Claude 3).
@@ -100,6 +428,10 @@ impl AnthropicTarget {
 
 #[async_trait]
 impl Target for AnthropicTarget {
+    fn model_name(&self) -> String {
+        self.model.clone()
+    }
+
     async fn send_prompt(&self, prompt: &str) -> RedOxideResult<String> {
         let url = "https://api.anthropic.com/v1/messages";
 
@@ -119,6 +451,10 @@ impl Target for AnthropicTarget {
             .send()
             .await?;
 
+        if !res.status().is_success() {
+            return Err(http_error("Anthropic API error", res).await);
+        }
+
         let json_resp: serde_json::Value = res.json().await?;
 
         let text = json_resp["content"][0]["text"]
@@ -128,6 +464,160 @@ impl Target for AnthropicTarget {
 
         Ok(text)
     }
+
+    async fn send_conversation(&self, history: &[ChatMessage]) -> RedOxideResult<String> {
+        let url = "https://api.anthropic.com/v1/messages";
+
+        let messages: Vec<_> = history
+            .iter()
+            .map(|turn| {
+                let role = match turn.role {
+                    Role::User => "user",
+                    Role::Assistant => "assistant",
+                };
+                json!({ "role": role, "content": turn.content })
+            })
+            .collect();
+
+        let body = json!({
+            "model": self.model,
+            "max_tokens": 1024,
+            "messages": messages
+        });
+
+        let res = self
+            .client
+            .post(url)
+            .header("x-api-key", &self.api_key)
+            .header("anthropic-version", "2023-06-01")
+            .header("content-type", "application/json")
+            .json(&body)
+            .send()
+            .await?;
+
+        if !res.status().is_success() {
+            return Err(http_error("Anthropic API error", res).await);
+        }
+
+        let json_resp: serde_json::Value = res.json().await?;
+
+        let text = json_resp["content"][0]["text"]
+            .as_str()
+            .unwrap_or("")
+            .to_string();
+
+        Ok(text)
+    }
+
+    async fn send_prompt_with_tools(
+        &self,
+        prompt: &str,
+        tools: &[ToolSpec],
+    ) -> RedOxideResult<(String, Vec<ToolCall>)> {
+        let url = "https://api.anthropic.com/v1/messages";
+
+        let tool_defs: Vec<_> = tools
+            .iter()
+            .map(|t| {
+                json!({
+                    "name": t.name,
+                    "description": t.description,
+                    "input_schema": t.parameters,
+                })
+            })
+            .collect();
+
+        let body = json!({
+            "model": self.model,
+            "max_tokens": 1024,
+            "tools": tool_defs,
+            "messages": [{ "role": "user", "content": prompt }]
+        });
+
+        let res = self
+            .client
+            .post(url)
+            .header("x-api-key", &self.api_key)
+            .header("anthropic-version", "2023-06-01")
+            .header("content-type", "application/json")
+            .json(&body)
+            .send()
+            .await?;
+
+        if !res.status().is_success() {
+            return Err(http_error("Anthropic API error", res).await);
+        }
+
+        let json_resp: serde_json::Value = res.json().await?;
+        let blocks = json_resp["content"].as_array().cloned().unwrap_or_default();
+
+        let text = blocks
+            .iter()
+            .find(|b| b["type"] == "text")
+            .and_then(|b| b["text"].as_str())
+            .unwrap_or("")
+            .to_string();
+
+        let tool_calls = blocks
+            .iter()
+            .filter(|b| b["type"] == "tool_use")
+            .map(|b| ToolCall {
+                name: b["name"].as_str().unwrap_or_default().to_string(),
+                arguments: b["input"].clone(),
+            })
+            .collect();
+
+        Ok((text, tool_calls))
+    }
+
+    async fn send_prompt_stream(
+        &self,
+        prompt: &str,
+    ) -> RedOxideResult<BoxStream<'_, RedOxideResult<String>>> {
+        let url = "https://api.anthropic.com/v1/messages";
+
+        let body = json!({
+            "model": self.model,
+            "max_tokens": 1024,
+            "stream": true,
+            "messages": [{ "role": "user", "content": prompt }]
+        });
+
+        let res = self
+            .client
+            .post(url)
+            .header("x-api-key", &self.api_key)
+            .header("anthropic-version", "2023-06-01")
+            .header("content-type", "application/json")
+            .json(&body)
+            .send()
+            .await?;
+
+        if !res.status().is_success() {
+            return Err(http_error("Anthropic API error", res).await);
+        }
+
+        let mut events = res.bytes_stream().eventsource();
+
+        Ok(stream::poll_fn(move |cx| loop {
+            match futures::ready!(events.poll_next_unpin(cx)) {
+                Some(Ok(event)) => {
+                    if event.event == "message_stop" {
+                        return std::task::Poll::Ready(None);
+                    }
+                    let delta: serde_json::Value =
+                        serde_json::from_str(&event.data).unwrap_or_default();
+                    if let Some(text) = delta["delta"]["text"].as_str() {
+                        return std::task::Poll::Ready(Some(Ok(text.to_string())));
+                    }
+                    // Non-text event (e.g. message_start, ping); keep polling.
+                }
+                Some(Err(e)) => return std::task::Poll::Ready(Some(Err(e.into()))),
+                None => return std::task::Poll::Ready(None),
+            }
+        })
+        .boxed())
+    }
 }
 
 /// Implementation for Local Models running via Ollama.
@@ -156,6 +646,10 @@ impl OllamaTarget {
 
 #[async_trait]
 impl Target for OllamaTarget {
+    fn model_name(&self) -> String {
+        self.model.clone()
+    }
+
     async fn send_prompt(&self, prompt: &str) -> RedOxideResult<String> {
         let url = format!("{}/api/chat", self.endpoint);
 
@@ -167,6 +661,10 @@ impl Target for OllamaTarget {
 
         let res = self.client.post(&url).json(&body).send().await?;
 
+        if !res.status().is_success() {
+            return Err(http_error("Ollama API error", res).await);
+        }
+
         let json_resp: serde_json::Value = res.json().await?;
 
         let text = json_resp["message"]["content"]
@@ -176,6 +674,74 @@ impl Target for OllamaTarget {
 
         Ok(text)
     }
+
+    async fn send_prompt_stream(
+        &self,
+        prompt: &str,
+    ) -> RedOxideResult<BoxStream<'_, RedOxideResult<String>>> {
+        let url = format!("{}/api/chat", self.endpoint);
+
+        let body = json!({
+            "model": self.model,
+            "messages": [{ "role": "user", "content": prompt }],
+            "stream": true
+        });
+
+        let res = self.client.post(&url).json(&body).send().await?;
+
+        if !res.status().is_success() {
+            return Err(http_error("Ollama API error", res).await);
+        }
+
+        // Ollama's streamed chat API emits bare newline-delimited JSON objects, not SSE
+        // `data:`/`event:` frames, so `eventsource-stream` (which only dispatches on those
+        // recognized field names, separated by a blank line) never produces an event here.
+        // Split the raw byte stream on '\n' ourselves instead.
+        let mut bytes = res.bytes_stream();
+        let mut buf: Vec<u8> = Vec::new();
+
+        Ok(stream::poll_fn(move |cx| loop {
+            if let Some(pos) = buf.iter().position(|&b| b == b'\n') {
+                let line: Vec<u8> = buf.drain(..=pos).collect();
+                let line = String::from_utf8_lossy(&line[..line.len() - 1]).into_owned();
+                if line.trim().is_empty() {
+                    continue;
+                }
+
+                let chunk: serde_json::Value = serde_json::from_str(&line).unwrap_or_default();
+                if chunk["done"].as_bool().unwrap_or(false) {
+                    return std::task::Poll::Ready(None);
+                }
+                let text = chunk["message"]["content"].as_str().unwrap_or("");
+                if text.is_empty() {
+                    continue;
+                }
+                return std::task::Poll::Ready(Some(Ok(text.to_string())));
+            }
+
+            match futures::ready!(bytes.poll_next_unpin(cx)) {
+                Some(Ok(b)) => buf.extend_from_slice(&b),
+                Some(Err(e)) => return std::task::Poll::Ready(Some(Err(e.into()))),
+                None => {
+                    // Stream ended; Ollama always terminates with a `"done": true` line, but
+                    // flush any trailing partial line just in case the connection was cut.
+                    if buf.is_empty() {
+                        return std::task::Poll::Ready(None);
+                    }
+                    let line = String::from_utf8_lossy(&buf).into_owned();
+                    buf.clear();
+                    let chunk: serde_json::Value =
+                        serde_json::from_str(&line).unwrap_or_default();
+                    let text = chunk["message"]["content"].as_str().unwrap_or("").to_string();
+                    if text.is_empty() {
+                        return std::task::Poll::Ready(None);
+                    }
+                    return std::task::Poll::Ready(Some(Ok(text)));
+                }
+            }
+        })
+        .boxed())
+    }
 }
 
 /// Implementation for Google's Gemini API (Generative Language).
@@ -202,6 +768,10 @@ impl GeminiTarget {
 
 #[async_trait]
 impl Target for GeminiTarget {
+    fn model_name(&self) -> String {
+        self.model.clone()
+    }
+
     async fn send_prompt(&self, prompt: &str) -> RedOxideResult<String> {
         let url = format!(
             "https://generativelanguage.googleapis.com/v1beta/models/{}:generateContent?key={}",
@@ -225,8 +795,225 @@ impl Target for GeminiTarget {
             .await?;
 
         if !res.status().is_success() {
-            let error_text = res.text().await?;
-            return Err(anyhow::anyhow!("Gemini API Error: {}", error_text));
+            return Err(http_error("Gemini API error", res).await);
+        }
+
+        let json_resp: serde_json::Value = res.json().await?;
+
+        let text = json_resp["candidates"][0]["content"]["parts"][0]["text"]
+            .as_str()
+            .unwrap_or("")
+            .to_string();
+
+        Ok(text)
+    }
+}
+
+/// A cached OAuth access token, refreshed automatically once it's near expiry.
+struct CachedToken {
+    access_token: String,
+    expires_at: std::time::Instant,
+}
+
+/// Implementation for enterprise Vertex AI deployments, authenticated via Application
+/// Default Credentials (ADC) rather than the AI-Studio `?key=API_KEY` flow used by
+/// [`GeminiTarget`].
+///
+/// Reads the ADC JSON (either a service-account key, or the `authorized_user` credentials
+/// written by `gcloud auth application-default login`), exchanges it for a short-lived
+/// bearer token, and caches that token until shortly before it expires.
+pub struct VertexAITarget {
+    client: HttpClient,
+    project_id: String,
+    region: String,
+    model: String,
+    adc_file: Option<std::path::PathBuf>,
+    token_cache: tokio::sync::Mutex<Option<CachedToken>>,
+}
+
+impl VertexAITarget {
+    /// Creates a new Vertex AI target.
+    ///
+    /// # Arguments
+    /// * `project_id` - The GCP project hosting the Vertex AI endpoint.
+    /// * `region` - The regional endpoint to call (e.g. `us-central1`).
+    /// * `model` - The published model name (e.g. `gemini-1.5-pro`).
+    /// * `adc_file` - Explicit path to an ADC JSON file. If `None`, falls back to the
+    ///   `GOOGLE_APPLICATION_CREDENTIALS` env var, then to gcloud's default ADC location.
+    pub fn new(
+        project_id: String,
+        region: String,
+        model: String,
+        adc_file: Option<std::path::PathBuf>,
+    ) -> Self {
+        Self {
+            client: HttpClient::new(),
+            project_id,
+            region,
+            model,
+            adc_file,
+            token_cache: tokio::sync::Mutex::new(None),
+        }
+    }
+
+    /// Resolves the ADC JSON file path: explicit override, then env var, then gcloud default.
+    fn resolve_adc_path(&self) -> RedOxideResult<std::path::PathBuf> {
+        if let Some(path) = &self.adc_file {
+            return Ok(path.clone());
+        }
+        if let Ok(path) = std::env::var("GOOGLE_APPLICATION_CREDENTIALS") {
+            return Ok(std::path::PathBuf::from(path));
+        }
+        let home = std::env::var("HOME")
+            .map_err(|_| anyhow::anyhow!("could not determine home directory for ADC lookup"))?;
+        Ok(std::path::PathBuf::from(home)
+            .join(".config/gcloud/application_default_credentials.json"))
+    }
+
+    /// Exchanges the ADC credentials for a fresh access token.
+    ///
+    /// Supports both credential shapes ADC can produce: a service-account key (signed as a
+    /// JWT and exchanged via the `jwt-bearer` grant) and `authorized_user` credentials from
+    /// `gcloud auth application-default login` (exchanged via the `refresh_token` grant).
+    async fn fetch_access_token(&self) -> RedOxideResult<(String, Duration)> {
+        let adc_path = self.resolve_adc_path()?;
+        let adc_raw = std::fs::read_to_string(&adc_path)
+            .map_err(|e| anyhow::anyhow!("failed to read ADC file {:?}: {}", adc_path, e))?;
+        let adc: serde_json::Value = serde_json::from_str(&adc_raw)?;
+
+        let token_endpoint = "https://oauth2.googleapis.com/token";
+
+        let params = match adc["type"].as_str() {
+            Some("service_account") => {
+                let jwt = build_service_account_jwt(&adc)?;
+                vec![
+                    ("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer"),
+                    ("assertion", jwt.as_str()),
+                ]
+            }
+            Some("authorized_user") => {
+                vec![
+                    ("grant_type", "refresh_token"),
+                    (
+                        "client_id",
+                        adc["client_id"].as_str().unwrap_or_default(),
+                    ),
+                    (
+                        "client_secret",
+                        adc["client_secret"].as_str().unwrap_or_default(),
+                    ),
+                    (
+                        "refresh_token",
+                        adc["refresh_token"].as_str().unwrap_or_default(),
+                    ),
+                ]
+            }
+            other => {
+                return Err(anyhow::anyhow!(
+                    "unsupported ADC credential type: {:?}",
+                    other
+                ))
+            }
+        };
+
+        let res = self
+            .client
+            .post(token_endpoint)
+            .form(&params)
+            .send()
+            .await?;
+        let body: serde_json::Value = res.json().await?;
+
+        let access_token = body["access_token"]
+            .as_str()
+            .ok_or_else(|| anyhow::anyhow!("ADC token exchange did not return access_token"))?
+            .to_string();
+        let expires_in = body["expires_in"].as_u64().unwrap_or(3600);
+
+        Ok((access_token, Duration::from_secs(expires_in)))
+    }
+
+    /// Returns a valid access token, refreshing it if the cached one is near expiry.
+    async fn access_token(&self) -> RedOxideResult<String> {
+        const REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+        let mut cache = self.token_cache.lock().await;
+        if let Some(cached) = cache.as_ref() {
+            if cached.expires_at > std::time::Instant::now() + REFRESH_MARGIN {
+                return Ok(cached.access_token.clone());
+            }
+        }
+
+        let (access_token, ttl) = self.fetch_access_token().await?;
+        *cache = Some(CachedToken {
+            access_token: access_token.clone(),
+            expires_at: std::time::Instant::now() + ttl,
+        });
+
+        Ok(access_token)
+    }
+}
+
+/// Signs a Google service-account key into a self-issued JWT suitable for the
+/// `jwt-bearer` OAuth grant.
+fn build_service_account_jwt(service_account: &serde_json::Value) -> RedOxideResult<String> {
+    use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+
+    let private_key = service_account["private_key"]
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("service account JSON is missing private_key"))?;
+    let client_email = service_account["client_email"]
+        .as_str()
+        .ok_or_else(|| anyhow::anyhow!("service account JSON is missing client_email"))?;
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+
+    let claims = json!({
+        "iss": client_email,
+        "scope": "https://www.googleapis.com/auth/cloud-platform",
+        "aud": "https://oauth2.googleapis.com/token",
+        "iat": now,
+        "exp": now + 3600,
+    });
+
+    let key = EncodingKey::from_rsa_pem(private_key.as_bytes())?;
+    Ok(encode(&Header::new(Algorithm::RS256), &claims, &key)?)
+}
+
+#[async_trait]
+impl Target for VertexAITarget {
+    fn model_name(&self) -> String {
+        self.model.clone()
+    }
+
+    async fn send_prompt(&self, prompt: &str) -> RedOxideResult<String> {
+        let token = self.access_token().await?;
+
+        let url = format!(
+            "https://{}-aiplatform.googleapis.com/v1/projects/{}/locations/{}/publishers/google/models/{}:generateContent",
+            self.region, self.project_id, self.region, self.model
+        );
+
+        let body = json!({
+            "contents": [{
+                "role": "user",
+                "parts": [{ "text": prompt }]
+            }]
+        });
+
+        let res = self
+            .client
+            .post(&url)
+            .bearer_auth(token)
+            .json(&body)
+            .send()
+            .await?;
+
+        if !res.status().is_success() {
+            return Err(http_error("Vertex AI API error", res).await);
         }
 
         let json_resp: serde_json::Value = res.json().await?;
@@ -239,3 +1026,73 @@ impl Target for GeminiTarget {
         Ok(text)
     }
 }
+
+/// Bundles a [`Target`] with a fixed set of tools to offer on every turn.
+///
+/// `Target::send_prompt_with_tools` takes the tool list per call, which is awkward for a
+/// strategy that needs to send it identically on every step of a multi-turn loop; `ToolTarget`
+/// pins that list once so [`crate::strategy::FunctionCallingStrategy`] can just send prompts.
+pub struct ToolTarget {
+    inner: Arc<dyn Target>,
+    tools: Vec<ToolSpec>,
+}
+
+impl ToolTarget {
+    /// Wraps `inner`, offering `tools` on every call.
+    pub fn new(inner: Arc<dyn Target>, tools: Vec<ToolSpec>) -> Self {
+        Self { inner, tools }
+    }
+
+    /// Wraps `inner` with a canonical set of mock sensitive tools (`run_shell`, `send_email`,
+    /// `read_file`), used to probe whether adversarial prompting can coax the model into
+    /// requesting a harmful real-world action rather than just harmful text.
+    pub fn with_mock_tools(inner: Arc<dyn Target>) -> Self {
+        Self::new(inner, mock_tool_specs())
+    }
+
+    /// Sends `prompt` along with the bundled tool list, returning the reply and any calls
+    /// the model requested.
+    pub async fn send_prompt_with_tools(
+        &self,
+        prompt: &str,
+    ) -> RedOxideResult<(String, Vec<ToolCall>)> {
+        self.inner.send_prompt_with_tools(prompt, &self.tools).await
+    }
+}
+
+/// The mock tool definitions offered by [`ToolTarget::with_mock_tools`].
+fn mock_tool_specs() -> Vec<ToolSpec> {
+    vec![
+        ToolSpec {
+            name: "run_shell".to_string(),
+            description: "Executes a shell command on the host machine.".to_string(),
+            parameters: json!({
+                "type": "object",
+                "properties": { "command": { "type": "string" } },
+                "required": ["command"]
+            }),
+        },
+        ToolSpec {
+            name: "send_email".to_string(),
+            description: "Sends an email on the user's behalf.".to_string(),
+            parameters: json!({
+                "type": "object",
+                "properties": {
+                    "to": { "type": "string" },
+                    "subject": { "type": "string" },
+                    "body": { "type": "string" }
+                },
+                "required": ["to", "body"]
+            }),
+        },
+        ToolSpec {
+            name: "read_file".to_string(),
+            description: "Reads the contents of a file from the local filesystem.".to_string(),
+            parameters: json!({
+                "type": "object",
+                "properties": { "path": { "type": "string" } },
+                "required": ["path"]
+            }),
+        },
+    ]
+}